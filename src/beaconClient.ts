@@ -3,7 +3,7 @@ import {capella, Slot} from "@lodestar/types"
 import { ssz } from "@lodestar/types";
 import {toHexString} from "@chainsafe/ssz";
 import {DOMAIN_SYNC_COMMITTEE} from "@chainsafe/lodestar-params";
-import { generateSigningRoot, aggregatePubkey } from "./utils/beacon.js";
+import { generateSigningRoot, aggregatePubkey, Network, GENESIS_VALIDATORS_ROOTS } from "./utils/beacon.js";
 import {bls} from "./bls.js";
 
 export type BeaconHeaderResponse = {
@@ -21,6 +21,12 @@ export type BeaconHeaderResponse = {
 	}
 }
 
+export type BeaconGenesisResponse = {
+	genesis_time: string;
+	genesis_validators_root: string;
+	genesis_fork_version: string;
+}
+
 export type BlockProof = {
 	blockRoot: string;
 	signingRoot: string;
@@ -36,9 +42,28 @@ export type SyncCommitteeSignature = {
 
 export class BeaconClient {
 	rpc: string;
+	network: Network;
 
-	constructor(rpc: string) {
+	constructor(rpc: string, network: Network) {
 		this.rpc = rpc;
+		this.network = network;
+	}
+
+	async getGenesis(): Promise<BeaconGenesisResponse> {
+		const endpoint = `${this.rpc}/eth/v1/beacon/genesis`
+		const resp = await this.getRequest(endpoint) as BeaconGenesisResponse;
+		return resp;
+	}
+
+	// ensures the beacon node is running on the network the client was configured for
+	async validateNetwork() {
+		const valRoot = (await this.getGenesis()).genesis_validators_root.toLowerCase();
+		if (valRoot !== GENESIS_VALIDATORS_ROOTS[this.network]) {
+			const nodeNetwork = Object.keys(GENESIS_VALIDATORS_ROOTS)
+				.find((n) => GENESIS_VALIDATORS_ROOTS[n] === valRoot) ?? `an unknown network (genesis validators root ${valRoot})`;
+			console.error(`Configured for ${this.network}, but the beacon node is on ${nodeNetwork}`)
+			process.exit(1)
+		}
 	}
 
 	getHead(): Promise<BeaconHeaderResponse> {
@@ -77,7 +102,7 @@ export class BeaconClient {
 	async getSigningRoot(block: capella.SignedBeaconBlock) {
 		const view = this.createView(ssz.capella.BeaconBlock, block.message);
 		const root = toHexString(view.hashTreeRoot());
-		return generateSigningRoot(block.message.slot, root, DOMAIN_SYNC_COMMITTEE, this.rpc);
+		return generateSigningRoot(block.message.slot, root, DOMAIN_SYNC_COMMITTEE, this.network);
 	}
 
 	async getSyncCommitteeSignature(slot: number): Promise<SyncCommitteeSignature> {
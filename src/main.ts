@@ -1,25 +1,25 @@
 import {BeaconClient} from "./beaconClient.js";
-import {aggregatePubkey, decodeSignerBits, verifyAggregateSignature} from "./utils/beacon.js";
+import {aggregatePubkey, decodeSignerBits, verifyAggregateSignature, Network, GENESIS_VALIDATORS_ROOTS} from "./utils/beacon.js";
 import {Message} from "./message.js";
 import {PublicKey} from "./pubkey.js";
 import {Signature} from "./signature.js";
-import { program } from 'commander';
+import { program, Option } from 'commander';
 import * as fs from 'fs';
 import {bls} from "./bls.js";
 import {toHexString} from "@chainsafe/ssz";
 import {ssz} from "@lodestar/types";
 
-async function fetchBlockProof(blockId: number | string, rpc: string) {
-	let client = new BeaconClient(rpc);
+async function fetchBlockProof(blockId: number | string, rpc: string, network: Network) {
+	let client = new BeaconClient(rpc, network);
 	const blockProof =  await client.getBlockProof(blockId);
 	const valid = await client.verifyBlockProof(blockProof)
 	console.log("Proof verifies:", valid)
 	return blockProof
 }
 
-async function fetchBlockProofPoints(blockId: number | string, rpc: string) {
-	let client = new BeaconClient(rpc);
-	const blockProof = await fetchBlockProof(blockId, rpc);
+async function fetchBlockProofPoints(blockId: number | string, rpc: string, network: Network) {
+	let client = new BeaconClient(rpc, network);
+	const blockProof = await fetchBlockProof(blockId, rpc, network);
 
 	const msg = await new Message(blockProof.signingRoot).hashToCurve();
 	const signature = await new Signature().fromBytes(blockProof.signature);
@@ -40,8 +40,8 @@ async function fetchBlockProofPoints(blockId: number | string, rpc: string) {
 	return result
 }
 
-async function fetchBlockSigners(blockId: number | string, rpc: string) {
-	let client = new BeaconClient(rpc);
+async function fetchBlockSigners(blockId: number | string, rpc: string, network: Network) {
+	let client = new BeaconClient(rpc, network);
 	const block = await client.getBlock(blockId)
 	const slot = ssz.Slot.fromJson(block.message.slot) // converts to number
 	const syncCommittee = await client.getSyncCommitteeSignature(slot)
@@ -77,6 +77,12 @@ function exportToJsonFile(filename: string, data: any) {
 	console.log(`Data has been written to ${filename}`);
 }
 
+function networkOption() {
+	return new Option('-n, --network <network>', 'Network the beacon node is running on.')
+		.choices(Object.keys(GENESIS_VALIDATORS_ROOTS))
+		.default('sepolia');
+}
+
 program
 	.version('0.1.0')
 	.description('Cairo Ethereum Consensus Verification Utils');
@@ -86,12 +92,14 @@ program
 	.description('Fetch proof for a blockm, containing everything needed for verification.')
 	.requiredOption('-b, --block <string | number>', 'Block hash or slot number.')
 	.requiredOption('-r, --rpc <string>', 'Beacon Chain RPC endpoint. (Quicknode free-tier recommended)')
+	.addOption(networkOption())
 	.option('-e, --export <path>', 'Path to export the results as a JSON file.')
 	.action(async (cmdObj) => {
-		const { block, rpc, export: exportPath } = cmdObj;
+		const { block, rpc, network, export: exportPath } = cmdObj;
+		await new BeaconClient(rpc, network).validateNetwork();
 
 		console.log(`Fetching block proof: ${block}`);
-		const result = await fetchBlockProof(block, rpc);
+		const result = await fetchBlockProof(block, rpc, network);
 		console.log(result)
 
 		if (exportPath) {
@@ -105,12 +113,14 @@ program
 	.description('Fetch the proof points needed for verification in cairo. This handles all preprocessing and exports in garaga conpatible decimals.')
 	.requiredOption('-b, --block <string | number>', 'Block hash or slot number.')
 	.requiredOption('-r, --rpc <string>', 'Beacon Chain RPC endpoint. (Quicknode free-tier recommended)')
+	.addOption(networkOption())
 	.option('-e, --export <path>', 'Path to export the results as a JSON file.')
 	.action(async (cmdObj) => {
-		const { block, rpc, export: exportPath } = cmdObj;
+		const { block, rpc, network, export: exportPath } = cmdObj;
+		await new BeaconClient(rpc, network).validateNetwork();
 
 		console.log(`Fetching block proof points: ${block}`);
-		const result = await fetchBlockProofPoints(block, rpc);
+		const result = await fetchBlockProofPoints(block, rpc, network);
 		console.log(result)
 
 		if (exportPath) {
@@ -123,12 +133,14 @@ program
 	.description('Fetches the signers of the block.')
 	.requiredOption('-b, --block <string | number>', 'Block hash or slot number.')
 	.requiredOption('-r, --rpc <string>', 'Beacon Chain RPC endpoint. (Quicknode free-tier recommended)')
+	.addOption(networkOption())
 	.option('-e, --export <path>', 'Path to export the results as a JSON file.')
 	.action(async (cmdObj) => {
-		const { block, rpc, export: exportPath } = cmdObj;
+		const { block, rpc, network, export: exportPath } = cmdObj;
+		await new BeaconClient(rpc, network).validateNetwork();
 
 		console.log(`Fetching block singers: ${block}`);
-		const result = await fetchBlockSigners(block, rpc);
+		const result = await fetchBlockSigners(block, rpc, network);
 		console.log(result)
 
 		if (exportPath) {
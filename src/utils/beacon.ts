@@ -1,25 +1,27 @@
 import { createBeaconConfig, } from "@lodestar/config";
 import { fromHexString, toHexString } from "@chainsafe/ssz";
-import axios from "axios";
 import { ssz } from "@lodestar/types";
 import { networksChainConfig } from "@lodestar/config/networks";
 import bls from "@chainsafe/bls";
 
-export const fetchGenesisValidatorRoot = async (rpc: string) => {
-    let res = await axios.get(rpc + "/eth/v1/beacon/genesis")
-        .then(res => res)
-    return res.data.data.genesis_validators_root
+export type Network = "mainnet" | "sepolia";
+
+// used for the signing domain and to detect an RPC endpoint on a different network
+export const GENESIS_VALIDATORS_ROOTS: Record<Network, string> = {
+    mainnet: "0x4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95",
+    sepolia: "0xd8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078",
 }
-export const getDomain = async (slot: number, domain: any, rpc: string) => {
-    const chainConfig = networksChainConfig.sepolia;
-    const valRoot = await fetchGenesisValidatorRoot(rpc);
-    const config = createBeaconConfig(chainConfig, fromHexString(valRoot));
+
+// expects the beacon node to be validated against the network (BeaconClient.validateNetwork)
+export const getDomain = (slot: number, domain: any, network: Network) => {
+    const chainConfig = networksChainConfig[network];
+    const config = createBeaconConfig(chainConfig, fromHexString(GENESIS_VALIDATORS_ROOTS[network]));
 
     return config.getDomain(slot, domain);
 }
 
-export const generateSigningRoot = async (slot: number, root: string, domainId: any, rpc: string) => {
-    const domain = await getDomain(slot, domainId, rpc);
+export const generateSigningRoot = (slot: number, root: string, domainId: any, network: Network) => {
+    const domain = getDomain(slot, domainId, network);
 
     const signingRoot = ssz.phase0.SigningData.hashTreeRoot({
         objectRoot: fromHexString(root),